@@ -1,4 +1,6 @@
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::hash::Hash;
 use std::ptr::{self, NonNull};
 
 pub struct Node<T> {
@@ -7,11 +9,19 @@ pub struct Node<T> {
     prev: Option<NonNull<Node<T>>>,
 }
 
-pub struct OwningList<T>(Option<Box<Node<T>>>);
+pub struct OwningList<T> {
+    head: Option<Box<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+}
 
 impl<T> Default for OwningList<T> {
     fn default() -> Self {
-        Self(None)
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+        }
     }
 }
 
@@ -28,7 +38,7 @@ where
 
 impl<T> OwningList<T> {
     pub fn prepend(&mut self, value: T) -> NonNull<Node<T>> {
-        let list_tail = self.0.take();
+        let list_tail = self.head.take();
         let mut head = Box::new(Node {
             value,
             next: list_tail,
@@ -38,24 +48,72 @@ impl<T> OwningList<T> {
         let head_ptr = unsafe { NonNull::new_unchecked(raw) };
         if let Some(list_tail) = &mut head.next {
             list_tail.prev = Some(head_ptr)
+        } else {
+            // the list was empty, so the new head is also the tail
+            self.tail = Some(head_ptr);
         }
-        self.0.replace(head);
+        self.head.replace(head);
+        self.len += 1;
         head_ptr
     }
 
+    pub fn push_front(&mut self, value: T) -> NonNull<Node<T>> {
+        self.prepend(value)
+    }
+
+    pub fn push_back(&mut self, value: T) -> NonNull<Node<T>> {
+        let mut old_tail = match self.tail {
+            Some(old_tail) => old_tail,
+            // the list is empty, pushing back is the same as pushing front
+            None => return self.push_front(value),
+        };
+        let mut node = Box::new(Node {
+            value,
+            next: None,
+            prev: Some(old_tail),
+        });
+        let raw = &mut *node as *mut Node<T>;
+        let node_ptr = unsafe { NonNull::new_unchecked(raw) };
+        unsafe { old_tail.as_mut() }.next = Some(node);
+        self.tail = Some(node_ptr);
+        self.len += 1;
+        node_ptr
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let mut head = self.head.take()?;
+        self.head = head.next.take();
+        match &mut self.head {
+            Some(new_head) => new_head.prev = None,
+            None => self.tail = None,
+        }
+        self.len -= 1;
+        Some(head.value)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        let node = self.remove_ptr(tail)?;
+        Some(node.value)
+    }
+
     pub fn move_to_head(&mut self, ptr: NonNull<Node<T>>) {
         // check if it's already at the head of the list
-        if let Some(existing) = &self.0 {
+        if let Some(existing) = &self.head {
             if ptr::eq(ptr.as_ptr(), existing.as_ref()) {
                 return;
             }
         }
         let mut head = self.remove_ptr(ptr).unwrap();
-        head.as_mut().next = self.0.take();
+        head.as_mut().next = self.head.take();
         if let Some(list_tail) = &mut head.next {
             list_tail.prev = Some(ptr)
+        } else {
+            // the list was empty after removing ptr, so it's also the tail now
+            self.tail = Some(ptr);
         }
-        self.0.replace(head);
+        self.head.replace(head);
+        self.len += 1;
     }
 
     // returns the pointed-to node
@@ -65,7 +123,7 @@ impl<T> OwningList<T> {
     }
 
     fn remove_to_owned(&mut self, item: &mut Node<T>) -> Option<Box<Node<T>>> {
-        if let Some(mut prev_ptr) = item.prev.take() {
+        let removed = if let Some(mut prev_ptr) = item.prev.take() {
             // not the head
             let mut prev = unsafe { prev_ptr.as_mut() };
             // careful, this contains "item". Don't use it, just return it
@@ -73,21 +131,252 @@ impl<T> OwningList<T> {
             if let Some(mut old_next) = item.next.take() {
                 old_next.prev = Some(prev_ptr);
                 prev.next = Some(old_next);
+            } else {
+                // item was the tail, so prev is the new tail
+                self.tail = Some(prev_ptr);
             }
             node
         } else {
             // is the head
             if let Some(mut next) = item.next.take() {
                 next.prev = None;
-                self.0.replace(next)
+                self.head.replace(next)
             } else {
-                self.0.take()
+                // item was the only node in the list
+                self.tail = None;
+                self.head.take()
             }
+        };
+        if removed.is_some() {
+            self.len -= 1;
         }
+        removed
     }
 
     pub fn iter(&self) -> ListIter<'_, T> {
-        ListIter::new(&self.0)
+        ListIter::new(&self.head)
+    }
+
+    pub fn iter_mut(&mut self) -> ListIterMut<'_, T> {
+        ListIterMut::new(&mut self.head)
+    }
+
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            list: self,
+            current: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // splices `other` onto the tail of this list in O(1), leaving `other` empty
+    pub fn append(&mut self, other: &mut OwningList<T>) {
+        let Some(mut other_head) = other.head.take() else {
+            return;
+        };
+        let other_tail = other.tail.take();
+        let other_len = std::mem::take(&mut other.len);
+
+        match self.tail {
+            Some(mut tail_ptr) => {
+                other_head.prev = Some(tail_ptr);
+                unsafe { tail_ptr.as_mut() }.next = Some(other_head);
+            }
+            None => self.head = Some(other_head),
+        }
+        self.tail = other_tail;
+        self.len += other_len;
+    }
+
+    // severs the list at `at`, returning everything from `at` onward as a
+    // new list
+    pub fn split_off(&mut self, mut at: NonNull<Node<T>>) -> OwningList<T> {
+        let mut suffix_len = 0usize;
+        let mut cursor = Some(at);
+        while let Some(node_ptr) = cursor {
+            suffix_len += 1;
+            cursor = unsafe { node_ptr.as_ref() }.next.as_deref().map(NonNull::from);
+        }
+        self.len -= suffix_len;
+
+        match unsafe { at.as_mut() }.prev.take() {
+            Some(mut prev_ptr) => {
+                let suffix_head = unsafe { prev_ptr.as_mut() }.next.take().unwrap();
+                let suffix_tail = self.tail.take();
+                self.tail = Some(prev_ptr);
+                OwningList {
+                    head: Some(suffix_head),
+                    tail: suffix_tail,
+                    len: suffix_len,
+                }
+            }
+            None => {
+                // `at` was already the head, so the whole list is the suffix
+                let suffix = OwningList {
+                    head: self.head.take(),
+                    tail: self.tail.take(),
+                    len: suffix_len,
+                };
+                self.len = 0;
+                suffix
+            }
+        }
+    }
+}
+
+impl<T> FromIterator<T> for OwningList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::default();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for OwningList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> Drop for OwningList<T> {
+    fn drop(&mut self) {
+        // drop the chain iteratively: recursive Box destruction would
+        // overflow the stack on a long list
+        let mut next = self.head.take();
+        while let Some(mut node) = next {
+            next = node.next.take();
+        }
+    }
+}
+
+// a cursor is either on a node, or on the "ghost" element just past the
+// back of the list (current == None), from which move_next/move_prev
+// re-enter at the head/tail
+pub struct CursorMut<'list, T> {
+    list: &'list mut OwningList<T>,
+    current: Option<NonNull<Node<T>>>,
+}
+
+impl<'list, T> CursorMut<'list, T> {
+    pub fn move_next(&mut self) {
+        self.current = match self.current {
+            Some(mut cur) => unsafe { cur.as_mut() }.next.as_deref_mut().map(NonNull::from),
+            None => self.list.head.as_deref_mut().map(NonNull::from),
+        };
+    }
+
+    pub fn move_prev(&mut self) {
+        self.current = match self.current {
+            Some(cur) => unsafe { cur.as_ref() }.prev,
+            None => self.list.tail,
+        };
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|mut ptr| &mut unsafe { ptr.as_mut() }.value)
+    }
+
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next_ptr = match self.current {
+            Some(cur) => unsafe { cur.as_ref() }.next.as_deref().map(NonNull::from),
+            None => self.list.head.as_deref().map(NonNull::from),
+        };
+        next_ptr.map(|mut ptr| &mut unsafe { ptr.as_mut() }.value)
+    }
+
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev_ptr = match self.current {
+            Some(cur) => unsafe { cur.as_ref() }.prev,
+            None => self.list.tail,
+        };
+        prev_ptr.map(|mut ptr| &mut unsafe { ptr.as_mut() }.value)
+    }
+
+    // splices a new node in before the current one; it becomes peek_prev()
+    pub fn insert_before(&mut self, value: T) {
+        let cur = match self.current {
+            Some(cur) => cur,
+            // ghost position: before the ghost is the back of the list
+            None => {
+                self.list.push_back(value);
+                return;
+            }
+        };
+        match unsafe { cur.as_ref() }.prev {
+            Some(mut prev_ptr) => {
+                let prev = unsafe { prev_ptr.as_mut() };
+                let cur_box = prev.next.take().unwrap();
+                let mut node = Box::new(Node {
+                    value,
+                    next: Some(cur_box),
+                    prev: Some(prev_ptr),
+                });
+                let raw = &mut *node as *mut Node<T>;
+                let node_ptr = unsafe { NonNull::new_unchecked(raw) };
+                node.next.as_mut().unwrap().prev = Some(node_ptr);
+                prev.next = Some(node);
+            }
+            None => {
+                // cur is the head
+                let head = self.list.head.take().unwrap();
+                let mut node = Box::new(Node {
+                    value,
+                    next: Some(head),
+                    prev: None,
+                });
+                let raw = &mut *node as *mut Node<T>;
+                let node_ptr = unsafe { NonNull::new_unchecked(raw) };
+                node.next.as_mut().unwrap().prev = Some(node_ptr);
+                self.list.head = Some(node);
+            }
+        }
+        self.list.len += 1;
+    }
+
+    // splices a new node in after the current one; it becomes peek_next()
+    pub fn insert_after(&mut self, value: T) {
+        let mut cur = match self.current {
+            Some(cur) => cur,
+            // ghost position: after the ghost is the front of the list
+            None => {
+                self.list.push_front(value);
+                return;
+            }
+        };
+        let cur_mut = unsafe { cur.as_mut() };
+        let next_box = cur_mut.next.take();
+        let mut node = Box::new(Node {
+            value,
+            next: next_box,
+            prev: Some(cur),
+        });
+        let raw = &mut *node as *mut Node<T>;
+        let node_ptr = unsafe { NonNull::new_unchecked(raw) };
+        match &mut node.next {
+            Some(next_node) => next_node.prev = Some(node_ptr),
+            None => self.list.tail = Some(node_ptr),
+        }
+        cur_mut.next = Some(node);
+        self.list.len += 1;
+    }
+
+    // removes the current node and returns its value, advancing the cursor
+    // to the node that followed it (or the ghost position, if it was last)
+    pub fn remove_current(&mut self) -> Option<T> {
+        let cur = self.current?;
+        let next_ptr = unsafe { cur.as_ref() }.next.as_deref().map(NonNull::from);
+        let removed = self.list.remove_ptr(cur)?;
+        self.current = next_ptr;
+        Some(removed.value)
     }
 }
 
@@ -96,8 +385,8 @@ impl<T> IntoIterator for OwningList<T> {
 
     type IntoIter = ListIntoIter<T>;
 
-    fn into_iter(self) -> Self::IntoIter {
-        ListIntoIter::new(self.0)
+    fn into_iter(mut self) -> Self::IntoIter {
+        ListIntoIter::new(self.head.take())
     }
 }
 
@@ -125,6 +414,29 @@ impl<'list, T> Iterator for ListIter<'list, T> {
     }
 }
 
+pub struct ListIterMut<'list, T> {
+    next: Option<&'list mut Node<T>>,
+}
+
+impl<'list, T> ListIterMut<'list, T> {
+    pub fn new(head: &'list mut Option<Box<Node<T>>>) -> Self {
+        Self {
+            next: head.as_deref_mut(),
+        }
+    }
+}
+
+impl<'list, T> Iterator for ListIterMut<'list, T> {
+    type Item = &'list mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            &mut node.value
+        })
+    }
+}
+
 pub struct ListIntoIter<T> {
     next: Option<Box<Node<T>>>,
 }
@@ -149,6 +461,170 @@ impl<T> Iterator for ListIntoIter<T> {
     }
 }
 
+impl<T> Drop for ListIntoIter<T> {
+    fn drop(&mut self) {
+        let mut next = self.next.take();
+        while let Some(mut node) = next {
+            next = node.next.take();
+        }
+    }
+}
+
+// An intrusive variant of the list: the `next`/`prev` pointers live inside
+// the caller's own type instead of a crate-owned `Node<T>`, so a single
+// pinned value can be linked into several lists at once with no extra heap
+// allocation per insertion. The caller is responsible for guaranteeing that
+// a node passed to these methods is not moved or freed while linked, and
+// that it actually belongs to the list it's passed to.
+pub trait Link {
+    type Target;
+
+    /// # Safety
+    ///
+    /// `target` must be a valid, properly aligned pointer to a live
+    /// `Self::Target` with no other live reference to it for the duration
+    /// of the call.
+    unsafe fn next(target: NonNull<Self::Target>) -> Option<NonNull<Self::Target>>;
+
+    /// # Safety
+    ///
+    /// `target` must be a valid, properly aligned pointer to a live
+    /// `Self::Target` with no other live reference to it for the duration
+    /// of the call.
+    unsafe fn prev(target: NonNull<Self::Target>) -> Option<NonNull<Self::Target>>;
+
+    /// # Safety
+    ///
+    /// `target` must be a valid, properly aligned pointer to a live
+    /// `Self::Target` with no other live reference to it for the duration
+    /// of the call.
+    unsafe fn set_next(target: NonNull<Self::Target>, next: Option<NonNull<Self::Target>>);
+
+    /// # Safety
+    ///
+    /// `target` must be a valid, properly aligned pointer to a live
+    /// `Self::Target` with no other live reference to it for the duration
+    /// of the call.
+    unsafe fn set_prev(target: NonNull<Self::Target>, prev: Option<NonNull<Self::Target>>);
+}
+
+pub struct IntrusiveList<L: Link> {
+    head: Option<NonNull<L::Target>>,
+    tail: Option<NonNull<L::Target>>,
+}
+
+impl<L: Link> Default for IntrusiveList<L> {
+    fn default() -> Self {
+        Self {
+            head: None,
+            tail: None,
+        }
+    }
+}
+
+impl<L: Link> IntrusiveList<L> {
+    /// # Safety
+    ///
+    /// `node` must be a valid, properly aligned pointer to a live
+    /// `L::Target` whose `L` link fields are not already in use by this or
+    /// any other list, and that outlives the time it stays linked.
+    pub unsafe fn push_front(&mut self, node: NonNull<L::Target>) {
+        L::set_prev(node, None);
+        L::set_next(node, self.head);
+        match self.head {
+            Some(old_head) => L::set_prev(old_head, Some(node)),
+            None => self.tail = Some(node),
+        }
+        self.head = Some(node);
+    }
+
+    /// # Safety
+    ///
+    /// `node` must be a valid, properly aligned pointer to a live
+    /// `L::Target` whose `L` link fields are not already in use by this or
+    /// any other list, and that outlives the time it stays linked.
+    pub unsafe fn push_back(&mut self, node: NonNull<L::Target>) {
+        L::set_next(node, None);
+        L::set_prev(node, self.tail);
+        match self.tail {
+            Some(old_tail) => L::set_next(old_tail, Some(node)),
+            None => self.head = Some(node),
+        }
+        self.tail = Some(node);
+    }
+
+    /// # Safety
+    ///
+    /// `node` must be a valid pointer that is currently linked into this
+    /// list (i.e. it was previously passed to `push_front`/`push_back` on
+    /// this list and has not since been unlinked).
+    pub unsafe fn unlink(&mut self, node: NonNull<L::Target>) {
+        let prev = L::prev(node);
+        let next = L::next(node);
+        match prev {
+            Some(prev) => L::set_next(prev, next),
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => L::set_prev(next, prev),
+            None => self.tail = prev,
+        }
+    }
+}
+
+// An LRU cache built on top of OwningList's recency-list primitives: the
+// list tracks recency order (most-recently-used at the head) and the map
+// gives keyed O(1) lookup into it. Each node stores its own key so that
+// evicting the tail can reach back into the map.
+pub struct LruCache<K, V> {
+    capacity: usize,
+    list: OwningList<(K, V)>,
+    map: HashMap<K, NonNull<Node<(K, V)>>>,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "LruCache capacity must be greater than zero");
+        Self {
+            capacity,
+            list: OwningList::default(),
+            map: HashMap::new(),
+        }
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let ptr = *self.map.get(key)?;
+        self.list.move_to_head(ptr);
+        // SAFETY: ptr came from self.map, so it still points at a live node
+        // owned by self.list
+        Some(unsafe { &ptr.as_ref().value.1 })
+    }
+
+    pub fn put(&mut self, key: K, value: V) {
+        if let Some(old_ptr) = self.map.remove(&key) {
+            self.list.remove_ptr(old_ptr);
+        }
+        let ptr = self.list.prepend((key.clone(), value));
+        self.map.insert(key, ptr);
+        if self.map.len() > self.capacity {
+            if let Some((evicted_key, _)) = self.list.pop_back() {
+                self.map.remove(&evicted_key);
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,4 +660,279 @@ mod tests {
         let _one_ptr = list.prepend(1);
         list.move_to_head(two_ptr);
     }
+
+    #[test]
+    fn push_and_pop_back() {
+        let mut list = OwningList::<usize>::default();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(to_vec(&list), vec![1, 2, 3]);
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(to_vec(&list), vec![1]);
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn push_and_pop_front() {
+        let mut list = OwningList::<usize>::default();
+        list.push_front(1);
+        list.push_front(2);
+        list.push_back(3);
+        assert_eq!(to_vec(&list), vec![2, 1, 3]);
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn removing_the_tail_keeps_tail_pointer_consistent() {
+        let mut list = OwningList::<usize>::default();
+        list.push_back(1);
+        list.push_back(2);
+        let three_ptr = list.push_back(3);
+        // three is the tail; removing it should make two the new tail
+        list.remove_ptr(three_ptr);
+        assert_eq!(to_vec(&list), vec![1, 2]);
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(to_vec(&list), vec![1]);
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn cursor_mut_walks_and_edits_in_place() {
+        let mut list = OwningList::<usize>::default();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut cursor = list.cursor_mut();
+        cursor.move_next(); // ghost -> 1
+        *cursor.current().unwrap() *= 10;
+        cursor.move_next(); // 1 -> 2
+        assert_eq!(cursor.current(), Some(&mut 2));
+        cursor.insert_before(99);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 99));
+        drop(cursor);
+
+        assert_eq!(to_vec(&list), vec![10, 99, 2, 3]);
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn drop_does_not_overflow_the_stack_on_long_lists() {
+        let mut list = OwningList::<usize>::default();
+        for i in 0..200_000 {
+            list.push_back(i);
+        }
+        drop(list);
+
+        let mut list = OwningList::<usize>::default();
+        for i in 0..200_000 {
+            list.push_back(i);
+        }
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(0));
+        drop(iter);
+    }
+
+    #[test]
+    fn iter_mut_edits_in_place() {
+        let mut list = OwningList::<usize>::default();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        for value in list.iter_mut() {
+            *value *= 10;
+        }
+        assert_eq!(to_vec(&list), vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn cursor_mut_removes_current_and_advances() {
+        let mut list = OwningList::<usize>::default();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut cursor = list.cursor_mut();
+        cursor.move_next(); // ghost -> 1
+        cursor.move_next(); // 1 -> 2
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 3));
+        drop(cursor);
+
+        assert_eq!(to_vec(&list), vec![1, 3]);
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn cursor_mut_insert_updates_len() {
+        let mut list = OwningList::<usize>::default();
+        list.push_back(1);
+        list.push_back(2);
+
+        let mut cursor = list.cursor_mut();
+        cursor.move_next(); // ghost -> 1
+        cursor.move_next(); // 1 -> 2
+        cursor.insert_before(99);
+        cursor.insert_after(100);
+        drop(cursor);
+
+        assert_eq!(to_vec(&list), vec![1, 99, 2, 100]);
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn len_tracks_pushes_and_pops() {
+        let mut list = OwningList::<usize>::default();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+        list.push_back(1);
+        list.push_back(2);
+        assert_eq!(list.len(), 2);
+        assert!(!list.is_empty());
+        list.pop_front();
+        assert_eq!(list.len(), 1);
+        list.pop_back();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn from_iter_and_extend_build_in_order() {
+        let mut list: OwningList<usize> = (1..=3).collect();
+        assert_eq!(to_vec(&list), vec![1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        list.extend([4, 5]);
+        assert_eq!(to_vec(&list), vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn append_splices_the_other_list_onto_the_tail() {
+        let mut a: OwningList<usize> = (1..=2).collect();
+        let mut b: OwningList<usize> = (3..=4).collect();
+        a.append(&mut b);
+        assert_eq!(to_vec(&a), vec![1, 2, 3, 4]);
+        assert_eq!(a.len(), 4);
+        assert_eq!(to_vec(&b), vec![]);
+        assert_eq!(b.len(), 0);
+        assert_eq!(a.pop_back(), Some(4));
+        assert_eq!(a.pop_back(), Some(3));
+    }
+
+    #[test]
+    fn split_off_severs_the_list_at_the_given_node() {
+        let mut list = OwningList::<usize>::default();
+        list.push_back(1);
+        let two_ptr = list.push_back(2);
+        list.push_back(3);
+
+        let suffix = list.split_off(two_ptr);
+        assert_eq!(to_vec(&list), vec![1]);
+        assert_eq!(list.len(), 1);
+        assert_eq!(to_vec(&suffix), vec![2, 3]);
+        assert_eq!(suffix.len(), 2);
+    }
+
+    struct Entry {
+        value: usize,
+        next: Option<NonNull<Entry>>,
+        prev: Option<NonNull<Entry>>,
+    }
+
+    struct EntryLink;
+
+    impl Link for EntryLink {
+        type Target = Entry;
+
+        unsafe fn next(target: NonNull<Entry>) -> Option<NonNull<Entry>> {
+            target.as_ref().next
+        }
+
+        unsafe fn prev(target: NonNull<Entry>) -> Option<NonNull<Entry>> {
+            target.as_ref().prev
+        }
+
+        unsafe fn set_next(mut target: NonNull<Entry>, next: Option<NonNull<Entry>>) {
+            target.as_mut().next = next;
+        }
+
+        unsafe fn set_prev(mut target: NonNull<Entry>, prev: Option<NonNull<Entry>>) {
+            target.as_mut().prev = prev;
+        }
+    }
+
+    fn entry_ptr(value: usize) -> NonNull<Entry> {
+        let boxed = Box::new(Entry {
+            value,
+            next: None,
+            prev: None,
+        });
+        NonNull::new(Box::into_raw(boxed)).unwrap()
+    }
+
+    unsafe fn entry_values(list: &IntrusiveList<EntryLink>) -> Vec<usize> {
+        let mut values = Vec::new();
+        let mut next = list.head;
+        while let Some(node) = next {
+            values.push(node.as_ref().value);
+            next = node.as_ref().next;
+        }
+        values
+    }
+
+    #[test]
+    fn intrusive_list_links_and_unlinks_in_place() {
+        let mut list = IntrusiveList::<EntryLink>::default();
+        let one = entry_ptr(1);
+        let two = entry_ptr(2);
+        let three = entry_ptr(3);
+
+        unsafe {
+            list.push_back(one);
+            list.push_back(two);
+            list.push_front(three);
+            assert_eq!(entry_values(&list), vec![3, 1, 2]);
+
+            list.unlink(one);
+            assert_eq!(entry_values(&list), vec![3, 2]);
+
+            list.unlink(three);
+            assert_eq!(entry_values(&list), vec![2]);
+
+            drop(Box::from_raw(one.as_ptr()));
+            drop(Box::from_raw(two.as_ptr()));
+            drop(Box::from_raw(three.as_ptr()));
+        }
+    }
+
+    #[test]
+    fn lru_cache_evicts_the_least_recently_used_entry() {
+        let mut cache = LruCache::<&'static str, usize>::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        assert_eq!(cache.get(&"a"), Some(&1)); // "a" is now most-recently-used
+        cache.put("c", 3); // evicts "b", the least-recently-used
+        assert_eq!(cache.get(&"b"), None);
+        assert_eq!(cache.get(&"a"), Some(&1));
+        assert_eq!(cache.get(&"c"), Some(&3));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn lru_cache_put_overwrites_existing_key() {
+        let mut cache = LruCache::<&'static str, usize>::new(2);
+        cache.put("a", 1);
+        cache.put("a", 2);
+        assert_eq!(cache.get(&"a"), Some(&2));
+        assert_eq!(cache.len(), 1);
+    }
 }